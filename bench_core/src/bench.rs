@@ -0,0 +1,111 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use clap::ValueEnum;
+use futures::stream::FuturesUnordered;
+use futures::StreamExt;
+
+use crate::memory::{self, MemoryReport};
+use crate::runtime::BenchRuntime;
+use crate::stats::{self, OutputFormat};
+use crate::Args;
+
+/// Which benchmark body to run against the selected runtime backend.
+#[derive(Clone, Copy, ValueEnum)]
+pub enum Mode {
+    JoinAll,
+    FuturesUnordered,
+    SpawnThroughput,
+    Memory,
+}
+
+/// Build `num_tasks` sleep futures (without spawning them) and wait for all
+/// of them to finish with `join_all`.
+async fn bench_join_all<R: BenchRuntime>(num_tasks: usize, sleep: Duration) {
+    let mut tasks = Vec::with_capacity(num_tasks);
+    for _ in 0..num_tasks {
+        tasks.push(R::sleep(sleep));
+    }
+    futures::future::join_all(tasks).await;
+}
+
+/// Time how long the runtime takes to enqueue `num_tasks` trivial tasks,
+/// exposing the per-spawn cost rather than the join path.
+async fn bench_spawn_throughput<R: BenchRuntime>(num_tasks: usize) {
+    let start = Instant::now();
+    for _ in 0..num_tasks {
+        R::spawn(async {});
+    }
+    let elapsed = start.elapsed();
+    let avg_ns = elapsed.as_nanos() as f64 / num_tasks as f64;
+    println!(
+        "spawn-throughput: {num_tasks} tasks in {elapsed:?} ({avg_ns:.2} ns/spawn)"
+    );
+}
+
+/// Drain the sleeps as they complete instead of joining them all at once,
+/// so staggered completions are observed rather than hidden behind `join_all`.
+async fn bench_futures_unordered<R: BenchRuntime>(num_tasks: usize, sleep: Duration) {
+    let mut set: FuturesUnordered<_> = (0..num_tasks).map(|_| R::sleep(sleep)).collect();
+    while set.next().await.is_some() {}
+}
+
+/// Sample RSS before spawning the tasks and again once they are all live,
+/// so the delta captures the scheduler's real per-task allocation cost
+/// rather than the size of an un-spawned future sitting in a `Vec`.
+///
+/// `BenchRuntime::spawn` hands back no join handle (each backend's handle
+/// type differs), so completion is tracked with a shared counter that
+/// spawned tasks decrement, polled via `R::sleep` rather than a
+/// backend-specific join.
+async fn bench_memory_profile<R: BenchRuntime>(num_tasks: usize, sleep: Duration) -> MemoryReport {
+    let before = memory::resident_set_size();
+    let remaining = Arc::new(AtomicUsize::new(num_tasks));
+    for _ in 0..num_tasks {
+        let remaining = Arc::clone(&remaining);
+        R::spawn(async move {
+            R::sleep(sleep).await;
+            remaining.fetch_sub(1, Ordering::SeqCst);
+        });
+    }
+    let peak = memory::resident_set_size();
+    while remaining.load(Ordering::SeqCst) > 0 {
+        R::sleep(Duration::from_millis(10)).await;
+    }
+    MemoryReport { before, peak }
+}
+
+fn run_once<R: BenchRuntime>(mode: Mode, num_tasks: usize, sleep: Duration) -> Duration {
+    let start = Instant::now();
+    match mode {
+        Mode::SpawnThroughput => R::block_on(bench_spawn_throughput::<R>(num_tasks)),
+        Mode::FuturesUnordered => R::block_on(bench_futures_unordered::<R>(num_tasks, sleep)),
+        Mode::JoinAll | Mode::Memory => R::block_on(bench_join_all::<R>(num_tasks, sleep)),
+    }
+    start.elapsed()
+}
+
+/// Run the benchmark selected by `args` against backend `R`.
+///
+/// `thread_env_var`, when set, receives `args.threads` before the backend's
+/// runtime is built, for backends that size their thread pool from an
+/// environment variable.
+pub fn run<R: BenchRuntime>(args: Args, thread_env_var: Option<&str>) {
+    if let (Some(threads), Some(var)) = (args.threads, thread_env_var) {
+        std::env::set_var(var, threads.to_string());
+    }
+    let sleep = Duration::from_secs(args.sleep_secs);
+
+    if matches!(args.mode, Mode::Memory) {
+        let start = Instant::now();
+        let report = R::block_on(bench_memory_profile::<R>(args.tasks, sleep));
+        let elapsed = start.elapsed();
+        report.print(args.tasks);
+        println!("wall-clock: {elapsed:?}");
+        return;
+    }
+
+    let stats = stats::repeat(args.iterations, || run_once::<R>(args.mode, args.tasks, sleep));
+    stats.print(OutputFormat::from(args.format));
+}
@@ -0,0 +1,57 @@
+use clap::{Parser, ValueEnum};
+
+use crate::bench::Mode;
+use crate::stats::OutputFormat;
+
+/// Benchmark task-scheduling behavior for a single async runtime backend.
+#[derive(Parser)]
+pub struct Args {
+    /// Number of tasks to spawn
+    #[arg(long)]
+    pub tasks: usize,
+
+    /// Which benchmark to run
+    #[arg(long, value_enum, default_value_t = Mode::JoinAll)]
+    pub mode: Mode,
+
+    /// How long each spawned task sleeps for, in seconds
+    #[arg(long, default_value_t = 10)]
+    pub sleep_secs: u64,
+
+    /// Worker threads to request from the backend, for backends that support it
+    #[arg(long)]
+    pub threads: Option<usize>,
+
+    /// Number of times to repeat the benchmark for the statistics harness
+    #[arg(long, default_value_t = 1, value_parser = parse_iterations)]
+    pub iterations: usize,
+
+    /// Output format for the statistics summary
+    #[arg(long, value_enum, default_value_t = Format::Table)]
+    pub format: Format,
+}
+
+/// Output format for the statistics summary, as exposed on the CLI.
+#[derive(Clone, Copy, ValueEnum)]
+pub enum Format {
+    Table,
+    Csv,
+}
+
+impl From<Format> for OutputFormat {
+    fn from(format: Format) -> Self {
+        match format {
+            Format::Table => OutputFormat::Table,
+            Format::Csv => OutputFormat::Csv,
+        }
+    }
+}
+
+fn parse_iterations(s: &str) -> Result<usize, String> {
+    let n: usize = s.parse().map_err(|_| format!("`{s}` is not a valid number"))?;
+    if n == 0 {
+        Err("--iterations must be at least 1".to_string())
+    } else {
+        Ok(n)
+    }
+}
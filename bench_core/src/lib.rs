@@ -0,0 +1,12 @@
+//! Shared benchmark bodies, CLI surface, and runtime-dispatch trait for the
+//! tokio / async-std / async-dispatcher benchmark binaries, so the benchmark
+//! logic is written once instead of once per backend.
+
+mod bench;
+mod cli;
+pub mod memory;
+pub mod runtime;
+pub mod stats;
+
+pub use bench::run;
+pub use cli::Args;
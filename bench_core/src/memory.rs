@@ -0,0 +1,88 @@
+//! Resident-set-size sampling, so task-count sweeps can report memory
+//! footprint alongside wall-clock time.
+
+/// Current resident set size, in bytes.
+#[cfg(target_os = "linux")]
+pub fn resident_set_size() -> u64 {
+    let statm = std::fs::read_to_string("/proc/self/statm").unwrap_or_default();
+    let pages: u64 = statm
+        .split_whitespace()
+        .nth(1)
+        .and_then(|s| s.parse().ok())
+        .unwrap_or(0);
+    pages * page_size()
+}
+
+#[cfg(target_os = "linux")]
+fn page_size() -> u64 {
+    // SAFETY: _SC_PAGESIZE has no preconditions and always succeeds.
+    unsafe { libc::sysconf(libc::_SC_PAGESIZE) as u64 }
+}
+
+/// Platforms without `/proc` have no cheap RSS probe; report zero rather
+/// than panicking so the rest of the harness still runs.
+#[cfg(not(target_os = "linux"))]
+pub fn resident_set_size() -> u64 {
+    0
+}
+
+/// Before/peak RSS bracketing a benchmark run.
+pub struct MemoryReport {
+    pub before: u64,
+    pub peak: u64,
+}
+
+impl MemoryReport {
+    pub fn total_bytes(&self) -> u64 {
+        self.peak.saturating_sub(self.before)
+    }
+
+    pub fn per_task_bytes(&self, num_tasks: usize) -> f64 {
+        if num_tasks == 0 {
+            0.0
+        } else {
+            self.total_bytes() as f64 / num_tasks as f64
+        }
+    }
+
+    pub fn print(&self, num_tasks: usize) {
+        println!(
+            "memory: total {} bytes, {:.2} bytes/task",
+            self.total_bytes(),
+            self.per_task_bytes(num_tasks)
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn total_bytes_is_the_before_peak_delta() {
+        let report = MemoryReport {
+            before: 1_000,
+            peak: 5_000,
+        };
+        assert_eq!(report.total_bytes(), 4_000);
+        assert_eq!(report.per_task_bytes(4), 1_000.0);
+    }
+
+    #[test]
+    fn total_bytes_saturates_instead_of_underflowing() {
+        let report = MemoryReport {
+            before: 5_000,
+            peak: 1_000,
+        };
+        assert_eq!(report.total_bytes(), 0);
+    }
+
+    #[test]
+    fn per_task_bytes_is_zero_for_zero_tasks() {
+        let report = MemoryReport {
+            before: 0,
+            peak: 1_000,
+        };
+        assert_eq!(report.per_task_bytes(0), 0.0);
+    }
+}
@@ -0,0 +1,98 @@
+use std::future::Future;
+use std::time::Duration;
+
+/// Minimal dispatch surface so the benchmark body can be written once and
+/// compiled against whichever async runtime backs this binary.
+pub trait BenchRuntime {
+    /// Block the current thread until `f` resolves, returning its output.
+    fn block_on<F: Future>(f: F) -> F::Output;
+
+    /// A future that resolves after `d` has elapsed, driven by this runtime's timer.
+    fn sleep(d: Duration) -> impl Future<Output = ()> + Send;
+
+    /// Hand `f` to the runtime's scheduler without waiting for it to complete.
+    fn spawn<F>(f: F)
+    where
+        F: Future<Output = ()> + Send + 'static;
+}
+
+#[cfg(feature = "tokio-runtime")]
+pub struct TokioRuntime;
+
+#[cfg(feature = "tokio-runtime")]
+impl BenchRuntime for TokioRuntime {
+    fn block_on<F: Future>(f: F) -> F::Output {
+        let mut builder = tokio::runtime::Builder::new_multi_thread();
+        if let Some(n) = env_usize("TOKIO_WORKER_THREADS") {
+            builder.worker_threads(n);
+        }
+        builder
+            .enable_all()
+            .build()
+            .expect("failed to build tokio runtime")
+            .block_on(f)
+    }
+
+    fn sleep(d: Duration) -> impl Future<Output = ()> + Send {
+        tokio::time::sleep(d)
+    }
+
+    fn spawn<F>(f: F)
+    where
+        F: Future<Output = ()> + Send + 'static,
+    {
+        tokio::spawn(f);
+    }
+}
+
+#[cfg(feature = "async-std-runtime")]
+pub struct AsyncStdRuntime;
+
+#[cfg(feature = "async-std-runtime")]
+impl BenchRuntime for AsyncStdRuntime {
+    fn block_on<F: Future>(f: F) -> F::Output {
+        async_std::task::block_on(f)
+    }
+
+    fn sleep(d: Duration) -> impl Future<Output = ()> + Send {
+        async_std::task::sleep(d)
+    }
+
+    fn spawn<F>(f: F)
+    where
+        F: Future<Output = ()> + Send + 'static,
+    {
+        async_std::task::spawn(f);
+    }
+}
+
+/// Spawns onto whatever native scheduler `async-dispatcher` finds installed,
+/// rather than owning a scheduler of its own. We install the crate's own
+/// `thread_dispatcher()`, which runs runnables on a single dedicated thread
+/// with a timer queue for delayed ones, instead of a thread per task.
+#[cfg(feature = "async-dispatcher-runtime")]
+pub struct DispatcherRuntime;
+
+#[cfg(feature = "async-dispatcher-runtime")]
+impl BenchRuntime for DispatcherRuntime {
+    fn block_on<F: Future>(f: F) -> F::Output {
+        async_dispatcher::set_dispatcher(async_dispatcher::thread_dispatcher());
+        async_dispatcher::block_on(f)
+    }
+
+    fn sleep(d: Duration) -> impl Future<Output = ()> + Send {
+        async_dispatcher::sleep(d)
+    }
+
+    fn spawn<F>(f: F)
+    where
+        F: Future<Output = ()> + Send + 'static,
+    {
+        async_dispatcher::spawn(f);
+    }
+}
+
+#[cfg(feature = "tokio-runtime")]
+fn env_usize(var: &str) -> Option<usize> {
+    std::env::var(var).ok().and_then(|s| s.parse().ok())
+}
@@ -0,0 +1,105 @@
+use std::time::Duration;
+
+/// Five-number summary of a set of timed runs.
+pub struct Stats {
+    pub mean: Duration,
+    pub stddev: Duration,
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    fn from_samples(mut samples: Vec<Duration>) -> Self {
+        samples.sort();
+        let n = samples.len();
+        let mean_secs = samples.iter().map(Duration::as_secs_f64).sum::<f64>() / n as f64;
+        let variance = samples
+            .iter()
+            .map(|d| {
+                let diff = d.as_secs_f64() - mean_secs;
+                diff * diff
+            })
+            .sum::<f64>()
+            / n as f64;
+
+        Stats {
+            mean: Duration::from_secs_f64(mean_secs),
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+            min: samples[0],
+            median: samples[n / 2],
+            max: samples[n - 1],
+        }
+    }
+
+    pub fn print(&self, format: OutputFormat) {
+        match format {
+            OutputFormat::Table => {
+                println!("{:>12} {:>12} {:>12} {:>12} {:>12}", "mean", "stddev", "min", "median", "max");
+                println!(
+                    "{:>12.6} {:>12.6} {:>12.6} {:>12.6} {:>12.6}",
+                    self.mean.as_secs_f64(),
+                    self.stddev.as_secs_f64(),
+                    self.min.as_secs_f64(),
+                    self.median.as_secs_f64(),
+                    self.max.as_secs_f64(),
+                );
+            }
+            OutputFormat::Csv => {
+                println!("mean,stddev,min,median,max");
+                println!(
+                    "{},{},{},{},{}",
+                    self.mean.as_secs_f64(),
+                    self.stddev.as_secs_f64(),
+                    self.min.as_secs_f64(),
+                    self.median.as_secs_f64(),
+                    self.max.as_secs_f64(),
+                );
+            }
+        }
+    }
+}
+
+/// How to render a `Stats` summary.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    Table,
+    Csv,
+}
+
+/// Run `run_once` `iterations` times and summarize the wall-clock durations it reports.
+pub fn repeat<F: FnMut() -> Duration>(iterations: usize, mut run_once: F) -> Stats {
+    let samples: Vec<Duration> = (0..iterations).map(|_| run_once()).collect();
+    Stats::from_samples(samples)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_samples_computes_five_number_summary() {
+        let samples = vec![
+            Duration::from_secs(3),
+            Duration::from_secs(1),
+            Duration::from_secs(2),
+        ];
+        let stats = Stats::from_samples(samples);
+
+        assert_eq!(stats.min, Duration::from_secs(1));
+        assert_eq!(stats.median, Duration::from_secs(2));
+        assert_eq!(stats.max, Duration::from_secs(3));
+        assert_eq!(stats.mean, Duration::from_secs(2));
+        assert!((stats.stddev.as_secs_f64() - (2f64 / 3f64).sqrt()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn from_samples_single_sample_has_zero_stddev() {
+        let stats = Stats::from_samples(vec![Duration::from_millis(500)]);
+
+        assert_eq!(stats.min, Duration::from_millis(500));
+        assert_eq!(stats.max, Duration::from_millis(500));
+        assert_eq!(stats.mean, Duration::from_millis(500));
+        assert_eq!(stats.stddev, Duration::ZERO);
+    }
+}
@@ -0,0 +1,7 @@
+use bench_core::runtime::DispatcherRuntime;
+use bench_core::Args;
+use clap::Parser;
+
+fn main() {
+    bench_core::run::<DispatcherRuntime>(Args::parse(), None);
+}
@@ -1,17 +1,9 @@
-use std::env;
-use async_std::task;
-use futures::future::join_all;
-use std::time::Duration;
+use bench_core::runtime::AsyncStdRuntime;
+use bench_core::Args;
+use clap::Parser;
 
-#[async_std::main]
-async fn main() {
-    let args: Vec<String> = env::args().collect();
-    let num_tasks = args[1].parse::<usize>().unwrap();
-    
-    let mut tasks = Vec::new();
-    for _ in 0..num_tasks {
-        tasks.push(task::sleep(Duration::from_secs(10)));
-    }
-
-    join_all(tasks).await;
+fn main() {
+    // async-std reads this on first use of its global executor, so it must
+    // be set before any task is run.
+    bench_core::run::<AsyncStdRuntime>(Args::parse(), Some("ASYNC_STD_THREAD_COUNT"));
 }
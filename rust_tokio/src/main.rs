@@ -1,13 +1,7 @@
-use std::env;
-use tokio::time::{sleep, Duration};
+use bench_core::runtime::TokioRuntime;
+use bench_core::Args;
+use clap::Parser;
 
-#[tokio::main]
-async fn main() {
-    let args: Vec<String> = env::args().collect();
-    let num_tasks = args[1].parse::<i32>().unwrap();
-    let mut tasks = Vec::new();
-    for _ in 0..num_tasks {
-        tasks.push(sleep(Duration::from_secs(10)));
-    }
-    futures::future::join_all(tasks).await;
-}
\ No newline at end of file
+fn main() {
+    bench_core::run::<TokioRuntime>(Args::parse(), Some("TOKIO_WORKER_THREADS"));
+}